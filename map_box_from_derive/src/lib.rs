@@ -0,0 +1,82 @@
+#![deny(missing_docs)]
+
+//! # map_box_from_derive
+//!
+//! The `#[derive(MapBoxFrom)]` proc-macro backing the `derive` feature of the `map_box_from` crate.
+//!
+//! See [`MapBoxFrom`](https://docs.rs/map_box_from) for the trait this macro implements.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+/// Derives [`MapBoxFrom`](../map_box_from/convert/trait.MapBoxFrom.html) for a `#[repr(transparent)]` struct wrapping a single `?Sized` field.
+///
+/// The generated impl reinterprets the incoming `Box<Field>` as `Box<Self>` via a raw pointer cast, exactly as shown in the hand-written `AsUnsized` example in the `map_box_from` docs,
+/// guarded by a `debug_assert!` that the pointee sizes agree. No allocation takes place.
+///
+/// # Requirements
+///
+/// The annotated type must be a `#[repr(transparent)]` struct with exactly one field. Any other shape - an enum, a struct with more than one field, or a struct missing
+/// `#[repr(transparent)]` - is rejected at compile time, since the pointer cast is only sound when layout is guaranteed identical to the field.
+#[proc_macro_derive(MapBoxFrom)]
+pub fn derive_map_box_from(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let Data::Struct(data) = &input.data else {
+        return syn::Error::new_spanned(&input, "MapBoxFrom can only be derived for structs")
+            .to_compile_error()
+            .into();
+    };
+
+    let is_repr_transparent = input.attrs.iter().any(|attr| {
+        attr.path().is_ident("repr")
+            && attr
+                .parse_args::<syn::Path>()
+                .is_ok_and(|path| path.is_ident("transparent"))
+    });
+    if !is_repr_transparent {
+        return syn::Error::new_spanned(
+            &input,
+            "MapBoxFrom can only be derived for #[repr(transparent)] structs",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let field = match &data.fields {
+        Fields::Named(fields) if fields.named.len() == 1 => &fields.named[0],
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+        _ => {
+            return syn::Error::new_spanned(
+                &input,
+                "MapBoxFrom can only be derived for structs with exactly one field",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+    let field_ty = &field.ty;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let expanded = quote! {
+        impl #impl_generics ::map_box_from::convert::MapBoxFrom<#field_ty> for #name #type_generics #where_clause {
+            fn map_box_from(value: ::std::boxed::Box<#field_ty>) -> ::std::boxed::Box<Self> {
+                let size_before = ::std::mem::size_of_val(&*value);
+                let ptr = ::std::boxed::Box::into_raw(value);
+                let boxed = unsafe {
+                    // SAFETY: `Self` is `#[repr(transparent)]` over `#field_ty`, so a pointer
+                    // to the field is a valid pointer to `Self` with identical layout.
+                    ::std::boxed::Box::from_raw(ptr as *mut Self)
+                };
+                // `size_of::<Self>()` is unavailable here since `Self` may be `?Sized`; compare
+                // via `size_of_val` on both pointees instead.
+                debug_assert!(size_before == ::std::mem::size_of_val(&*boxed));
+                boxed
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
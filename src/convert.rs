@@ -1,5 +1,14 @@
 use tap::Pipe as _;
 
+/// Traits for defining conversions between [Rc](std::rc::Rc) type parameters.
+pub mod rc;
+
+/// Traits for defining conversions between [Arc](std::sync::Arc) type parameters.
+pub mod arc;
+
+/// Traits for defining conversions between [Pin](std::pin::Pin)-ed [Box] type parameters.
+pub mod pin;
+
 /// A variation of [From] having both input and output be [Box]-ed - allowing implementations for unsized type parameters and following looser guidelines. It is the reciprocal of
 /// [MapBoxInto].
 ///
@@ -106,3 +115,89 @@ where T: Into<R>
         .into()
     }
 }
+
+/// Reaches unsizing coercions, e.g. `Box<T>` to `Box<dyn Trait>`, through the same generic vocabulary as [MapBoxFrom].
+///
+/// This is a separate trait rather than another [MapBoxFrom] blanket: a blanket keyed on [core::marker::Unsize] would conflict with the [`T: Into<R>`][Into] blanket above, since
+/// coherence cannot prove the two bounds are disjoint for generic `T`/`R`/`U`, even though `Unsize` is never satisfied between two distinct [Sized] types in practice.
+///
+/// Requires the `coerce_unsized` feature, since [core::marker::Unsize] is a nightly-only marker trait.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(unsize)]
+/// use std::io::Write;
+/// use map_box_from::convert::UnsizeBoxFrom;
+///
+/// let w: Box<dyn Write> = <dyn Write>::unsize_box_from(Box::new(Vec::new()));
+/// ```
+#[cfg(feature = "coerce_unsized")]
+pub trait UnsizeBoxFrom<T> {
+    /// Converts to this type from the input type via an unsizing coercion.
+    #[must_use]
+    fn unsize_box_from(value: Box<T>) -> Box<Self>;
+}
+
+#[cfg(feature = "coerce_unsized")]
+impl<T, U: ?Sized> UnsizeBoxFrom<T> for U
+where T: core::marker::Unsize<U>
+{
+    fn unsize_box_from(value: Box<T>) -> Box<Self> {
+        value as Box<U>
+    }
+}
+
+/// A variation of [TryFrom] having both input and output be [Box]-ed - allowing implementations for unsized type parameters and following looser guidelines. It is the reciprocal of
+/// [TryMapBoxInto].
+///
+/// Most guidelines applying to [TryFrom] and [TryInto] should also apply to [TryMapBoxFrom] and [TryMapBoxInto], with the exception that lossy conversions are perfectly acceptable.
+///
+/// # Generic Implementations
+///
+/// - `TryMapBoxFrom<T> for U` implies [`TryMapBoxInto`]`<U> for T where T: ?Sized, U: ?Sized`
+/// - `impl<T, R> TryMapBoxFrom<T> for R where T: TryInto<R>`
+///
+/// # Beware: failed conversions do not return the original `Box`
+///
+/// Because [TryInto] consumes its input by value, a failed conversion has nowhere to put the original `Box<T>` back - the box was already unboxed before the attempt. Users who need to
+/// recover the original value on failure must encode it inside their own `Error` type.
+pub trait TryMapBoxFrom<T: ?Sized> {
+    /// The type returned in the event of a conversion error.
+    type Error;
+
+    /// Tries to convert to this type from the input type.
+    fn try_map_box_from(value: Box<T>) -> Result<Box<Self>, Self::Error>;
+}
+
+/// The opposite of [`TryMapBoxFrom`]. See [TryMapBoxFrom] for more comprehensive documentation.
+pub trait TryMapBoxInto<T: ?Sized> {
+    /// The type returned in the event of a conversion error.
+    type Error;
+
+    /// Tries to convert this type into the (usually inferred) input type.
+    fn try_map_box_into(self: Box<Self>) -> Result<Box<T>, Self::Error>;
+}
+
+impl<T: ?Sized, R: ?Sized> TryMapBoxInto<R> for T
+where R: TryMapBoxFrom<T>
+{
+    type Error = R::Error;
+
+    fn try_map_box_into(self: Box<Self>) -> Result<Box<R>, Self::Error> {
+        R::try_map_box_from(self)
+    }
+}
+
+impl<T, R> TryMapBoxFrom<T> for R
+where T: TryInto<R>
+{
+    type Error = <T as TryInto<R>>::Error;
+
+    fn try_map_box_from(value: Box<T>) -> Result<Box<Self>, Self::Error> {
+        value
+        .pipe(|i| *i)
+        .try_into()
+        .map(Box::new)
+    }
+}
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use tap::Pipe as _;
+
+/// A variation of [From] having both input and output be [Arc]-ed - allowing implementations for unsized type parameters and following looser guidelines. It is the reciprocal of
+/// [MapArcInto].
+///
+/// See [`MapBoxFrom`] for the full rationale; this trait follows the same shape, substituting [Arc] for [Box] so that refcounted unsized conversions (e.g. lifting an `Arc<ConcreteNode>`
+/// into an `Arc<dyn Node>`) do not need to pass through [Box] first, which would break aliasing.
+///
+/// # Generic Implementations
+///
+/// - `MapArcFrom<T> for U` implies [`MapArcInto`]`<U> for T where T: ?Sized, U: ?Sized`
+/// - `impl<T, R> MapArcFrom<T> for R where T: Into<R> + Clone`
+///
+/// Unlike [Box], [Arc] does not guarantee unique ownership, so the sized blanket additionally requires `T: Clone`: it unwraps the `Arc` when uniquely owned and falls back to cloning
+/// otherwise, via [`Arc::unwrap_or_clone`].
+///
+/// # Examples
+///
+/// ```
+/// use map_box_from::convert::arc::MapArcFrom;
+/// use std::sync::Arc;
+///
+/// let value: Arc<i32> = Arc::new(5);
+/// let converted: Arc<i64> = i64::map_arc_from(value);
+/// assert_eq!(*converted, 5);
+/// ```
+///
+/// [`MapBoxFrom`]: crate::convert::MapBoxFrom
+/// [Box]: std::boxed::Box
+pub trait MapArcFrom<T: ?Sized> {
+    /// Converts to this type from the input type.
+    #[must_use]
+    fn map_arc_from(value: Arc<T>) -> Arc<Self>;
+}
+
+/// The opposite of [`MapArcFrom`]. See [MapArcFrom] for more comprehensive documentation.
+pub trait MapArcInto<T: ?Sized> {
+    /// Converts this type into the (usually inferred) input type.
+    #[must_use]
+    fn map_arc_into(self: Arc<Self>) -> Arc<T>;
+}
+
+impl<T: ?Sized, R: ?Sized> MapArcInto<R> for T
+where R: MapArcFrom<T>
+{
+    fn map_arc_into(self: Arc<Self>) -> Arc<R> {
+        R::map_arc_from(self)
+    }
+}
+
+impl<T, R> MapArcFrom<T> for R
+where T: Into<R> + Clone
+{
+    fn map_arc_from(value: Arc<T>) -> Arc<Self> {
+        value
+        .pipe(Arc::unwrap_or_clone)
+        .into()
+        .pipe(Arc::new)
+    }
+}
@@ -0,0 +1,55 @@
+use std::pin::Pin;
+
+use crate::convert::MapBoxFrom;
+
+/// A variation of [`MapBoxFrom`] having both input and output be [Pin]-ed - allowing conversions between boxed, pinned values such as the common `Pin<Box<dyn Future>>` idiom. It is the
+/// reciprocal of [MapPinBoxInto].
+///
+/// # Generic Implementations
+///
+/// - `MapPinBoxFrom<T> for U` implies [`MapPinBoxInto`]`<U> for T where T: ?Sized, U: ?Sized`
+/// - `impl<T: ?Sized + Unpin, R: ?Sized> MapPinBoxFrom<T> for R where R: MapBoxFrom<T>`
+///
+/// # `!Unpin` pointees
+///
+/// The blanket implementation only covers `T: Unpin`, since [`Pin::into_inner`] requires it to soundly move the pointee out of the pin. For `!Unpin` pointees - self-referential types
+/// chief among them - users must provide a bespoke implementation that preserves the pinning invariant and never moves the pointee.
+///
+/// # Examples
+///
+/// ```
+/// use map_box_from::convert::pin::MapPinBoxFrom;
+/// use std::pin::Pin;
+///
+/// let value: Pin<Box<i32>> = Box::into_pin(Box::new(5));
+/// let converted: Pin<Box<i64>> = i64::map_pin_box_from(value);
+/// assert_eq!(*converted, 5);
+/// ```
+pub trait MapPinBoxFrom<T: ?Sized> {
+    /// Converts to this type from the input type.
+    #[must_use]
+    fn map_pin_box_from(value: Pin<Box<T>>) -> Pin<Box<Self>>;
+}
+
+/// The opposite of [`MapPinBoxFrom`]. See [MapPinBoxFrom] for more comprehensive documentation.
+pub trait MapPinBoxInto<T: ?Sized> {
+    /// Converts this type into the (usually inferred) input type.
+    #[must_use]
+    fn map_pin_box_into(self: Pin<Box<Self>>) -> Pin<Box<T>>;
+}
+
+impl<T: ?Sized, R: ?Sized> MapPinBoxInto<R> for T
+where R: MapPinBoxFrom<T>
+{
+    fn map_pin_box_into(self: Pin<Box<Self>>) -> Pin<Box<R>> {
+        R::map_pin_box_from(self)
+    }
+}
+
+impl<T: ?Sized + Unpin, R: ?Sized> MapPinBoxFrom<T> for R
+where R: MapBoxFrom<T>
+{
+    fn map_pin_box_from(value: Pin<Box<T>>) -> Pin<Box<Self>> {
+        Box::into_pin(R::map_box_from(Pin::into_inner(value)))
+    }
+}
@@ -0,0 +1,62 @@
+use std::rc::Rc;
+
+use tap::Pipe as _;
+
+/// A variation of [From] having both input and output be [Rc]-ed - allowing implementations for unsized type parameters and following looser guidelines. It is the reciprocal of
+/// [MapRcInto].
+///
+/// See [`MapBoxFrom`] for the full rationale; this trait follows the same shape, substituting [Rc] for [Box] so that refcounted unsized conversions (e.g. lifting an `Rc<ConcreteNode>`
+/// into an `Rc<dyn Node>`) do not need to pass through [Box] first, which would break aliasing.
+///
+/// # Generic Implementations
+///
+/// - `MapRcFrom<T> for U` implies [`MapRcInto`]`<U> for T where T: ?Sized, U: ?Sized`
+/// - `impl<T, R> MapRcFrom<T> for R where T: Into<R> + Clone`
+///
+/// Unlike [Box], [Rc] does not guarantee unique ownership, so the sized blanket additionally requires `T: Clone`: it unwraps the `Rc` when uniquely owned and falls back to cloning
+/// otherwise, via [`Rc::unwrap_or_clone`].
+///
+/// # Examples
+///
+/// ```
+/// use map_box_from::convert::rc::MapRcFrom;
+/// use std::rc::Rc;
+///
+/// let value: Rc<i32> = Rc::new(5);
+/// let converted: Rc<i64> = i64::map_rc_from(value);
+/// assert_eq!(*converted, 5);
+/// ```
+///
+/// [`MapBoxFrom`]: crate::convert::MapBoxFrom
+/// [Box]: std::boxed::Box
+pub trait MapRcFrom<T: ?Sized> {
+    /// Converts to this type from the input type.
+    #[must_use]
+    fn map_rc_from(value: Rc<T>) -> Rc<Self>;
+}
+
+/// The opposite of [`MapRcFrom`]. See [MapRcFrom] for more comprehensive documentation.
+pub trait MapRcInto<T: ?Sized> {
+    /// Converts this type into the (usually inferred) input type.
+    #[must_use]
+    fn map_rc_into(self: Rc<Self>) -> Rc<T>;
+}
+
+impl<T: ?Sized, R: ?Sized> MapRcInto<R> for T
+where R: MapRcFrom<T>
+{
+    fn map_rc_into(self: Rc<Self>) -> Rc<R> {
+        R::map_rc_from(self)
+    }
+}
+
+impl<T, R> MapRcFrom<T> for R
+where T: Into<R> + Clone
+{
+    fn map_rc_from(value: Rc<T>) -> Rc<Self> {
+        value
+        .pipe(Rc::unwrap_or_clone)
+        .into()
+        .pipe(Rc::new)
+    }
+}
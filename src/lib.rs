@@ -12,12 +12,62 @@
 //! - Define bespoke traits fitting the usecase
 //! 
 //! This crate chooses the second method and provide [MapBoxFrom] and [MapBoxInto] traits that instead operates on [Box] to allow the use of unsized type parameters. While this crate maps all existing [Into] implementations into [MapBoxFrom] implementations, unsized type parameters are untouched and users are free to add new blanket implementations while avoiding conflict with blanket implemetations in [Sized] land.
-//! 
-//! **Note:** `TryMapBoxFrom` and `TryMapBoxInto` have not been added due to some considerations about how their blanket implementations should be added.
-//! 
+//!
+//! [TryMapBoxFrom] and [TryMapBoxInto] mirror [MapBoxFrom]/[MapBoxInto] for fallible conversions, porting existing [TryInto] implementations the same way. Because [TryInto] consumes its
+//! input, a failed conversion cannot hand the original [Box] back to the caller - users needing recovery must encode the original value inside their `Error` type.
+//!
+//! With the `coerce_unsized` feature enabled, [UnsizeBoxFrom] reaches unsizing coercions (`Box<T>` to `Box<dyn Trait>`) through the same generic vocabulary. It is a deliberately separate
+//! trait from [MapBoxFrom] rather than another blanket on it: a blanket keyed on [core::marker::Unsize] would conflict with the [`T: Into<R>`][Into] blanket, since coherence cannot prove
+//! the two bounds are disjoint for generic type parameters.
+//!
+//! [`convert::rc`] and [`convert::arc`] provide [MapRcFrom]/[MapRcInto] and [MapArcFrom]/[MapArcInto], the same shape as [MapBoxFrom]/[MapBoxInto] but for [Rc]/[Arc], so refcounted
+//! unsized conversions don't need to pass through [Box] first. [`convert::pin`] provides [MapPinBoxFrom]/[MapPinBoxInto] for the common `Pin<Box<dyn Trait>>` idiom, with a blanket
+//! implementation over [MapBoxFrom] for `T: Unpin` pointees.
+//!
 //! [Debug]: std::fmt::Debug
+//! [Rc]: std::rc::Rc
+//! [Arc]: std::sync::Arc
 //! [MapBoxFrom]: convert::MapBoxFrom
 //! [MapBoxInto]: convert::MapBoxInto
+//! [TryMapBoxFrom]: convert::TryMapBoxFrom
+//! [TryMapBoxInto]: convert::TryMapBoxInto
+//! [MapRcFrom]: convert::rc::MapRcFrom
+//! [MapRcInto]: convert::rc::MapRcInto
+//! [MapArcFrom]: convert::arc::MapArcFrom
+//! [MapArcInto]: convert::arc::MapArcInto
+//! [MapPinBoxFrom]: convert::pin::MapPinBoxFrom
+//! [MapPinBoxInto]: convert::pin::MapPinBoxInto
+//! [UnsizeBoxFrom]: convert::UnsizeBoxFrom
+//!
+//! With the `derive` feature enabled, `#[derive(MapBoxFrom)]` generates the checked pointer-cast [MapBoxFrom] impl for `#[repr(transparent)]` wrapper structs, turning the hand-audited
+//! `unsafe` plumbing from the [`MapBoxFrom`] example into a one-line annotation.
+#![cfg_attr(feature = "coerce_unsized", feature(unsize))]
 
 /// Traits for defining conversions between [Box] type parameters.
 pub mod convert;
+
+/// Derives [`MapBoxFrom`](convert::MapBoxFrom) for a `#[repr(transparent)]` struct wrapping a single `?Sized` field.
+///
+/// See [`map_box_from_derive::MapBoxFrom`] for the full documentation of what is generated and why it is restricted to `#[repr(transparent)]`, single-field structs.
+///
+/// # Examples
+///
+/// Wrapping a trait object:
+///
+/// ```
+/// use map_box_from::convert::MapBoxFrom;
+/// use map_box_from::MapBoxFrom as DeriveMapBoxFrom;
+///
+/// #[repr(transparent)]
+/// #[derive(DeriveMapBoxFrom)]
+/// struct DebugWrapper(dyn std::fmt::Debug);
+///
+/// let boxed: Box<dyn std::fmt::Debug> = Box::new(42_i32);
+/// let wrapped: Box<DebugWrapper> = DebugWrapper::map_box_from(boxed);
+/// assert_eq!(format!("{:?}", &wrapped.0), "42");
+/// ```
+///
+/// A wrapper whose field type is a bare type parameter, such as `struct Wrapper<T: ?Sized>(T)`, is not supported: the generated impl overlaps the
+/// `impl<T, R> MapBoxFrom<T> for R where T: Into<R>` blanket and fails to compile.
+#[cfg(feature = "derive")]
+pub use map_box_from_derive::MapBoxFrom;